@@ -0,0 +1,113 @@
+/// A condition on which a word boundary is recognised while segmenting a string,
+/// used by [Config::set_boundaries](crate::Config::set_boundaries)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// A literal space. The space itself is dropped.
+    Space,
+    /// A literal hyphen. The hyphen itself is dropped.
+    Hyphen,
+    /// A literal underscore. The underscore itself is dropped.
+    Underscore,
+    /// A literal dot. The dot itself is dropped.
+    Dot,
+    /// A literal slash. The slash itself is dropped.
+    Slash,
+    /// A lowercase letter followed by an uppercase letter, e.g. `oB` in `fooBar`.
+    LowerUpper,
+    /// Two uppercase letters followed by a lowercase letter, e.g. `LHt` in `XMLHttp`,
+    /// so the last capital begins a new word (`XML`, `Http`).
+    UpperUpperLower,
+    /// A lowercase letter followed by a digit, e.g. `o1` in `foo1`.
+    LowerDigit,
+    /// A digit followed by a lowercase letter, e.g. `1f` in `1foo`.
+    DigitLower,
+    /// An uppercase letter followed by a digit, e.g. `O1` in `FOO1`.
+    UpperDigit,
+    /// A digit followed by an uppercase letter, e.g. `1F` in `1FOO`.
+    DigitUpper,
+    /// Any other character that isn't a letter or digit, e.g. `,`, `@`, `#`, `!`. Dropped just
+    /// like the literal delimiters above, so stray punctuation never ends up embedded in a word.
+    Symbol,
+}
+
+/// The boundaries recognised by [Config::default](crate::Config::default).
+///
+/// These split on the lower→upper and upper-upper-lower case transitions and on the digit→upper
+/// transition (so `"foo1Bar"` splits into `foo1`/`Bar`), but not on the other digit transitions
+/// (so `"TestV2"` keeps `V2` together). [Boundary::Symbol] makes any other run of
+/// non-alphanumeric characters — a comma, an `@`, an exclamation mark — a dropped separator
+/// rather than something kept verbatim in the word.
+pub fn defaults() -> Vec<Boundary> {
+    vec![
+        Boundary::Space,
+        Boundary::Hyphen,
+        Boundary::Underscore,
+        Boundary::Dot,
+        Boundary::Slash,
+        Boundary::LowerUpper,
+        Boundary::UpperUpperLower,
+        Boundary::DigitUpper,
+        Boundary::Symbol,
+    ]
+}
+
+fn is_delimiter(c: char, boundaries: &[Boundary]) -> bool {
+    boundaries.iter().any(|boundary| match boundary {
+        Boundary::Space => c == ' ',
+        Boundary::Hyphen => c == '-',
+        Boundary::Underscore => c == '_',
+        Boundary::Dot => c == '.',
+        Boundary::Slash => c == '/',
+        Boundary::Symbol => !c.is_alphanumeric(),
+        _ => false,
+    })
+}
+
+fn splits_before(chars: &[char], i: usize, boundaries: &[Boundary]) -> bool {
+    let prev = chars[i - 1];
+    let cur = chars[i];
+    boundaries.iter().any(|boundary| match boundary {
+        Boundary::LowerUpper => prev.is_lowercase() && cur.is_uppercase(),
+        Boundary::UpperUpperLower => {
+            prev.is_uppercase() && cur.is_uppercase() && chars.get(i + 1).is_some_and(|v| v.is_lowercase())
+        }
+        Boundary::LowerDigit => prev.is_lowercase() && cur.is_ascii_digit(),
+        Boundary::DigitLower => prev.is_ascii_digit() && cur.is_lowercase(),
+        Boundary::UpperDigit => prev.is_uppercase() && cur.is_ascii_digit(),
+        Boundary::DigitUpper => prev.is_ascii_digit() && cur.is_uppercase(),
+        _ => false,
+    })
+}
+
+/// Split a string into words by walking it char-by-char and cutting at the enabled boundaries,
+/// dropping the delimiter boundaries (`Space`, `Hyphen`, `Underscore`, `Dot`, `Slash`, `Symbol`) themselves
+/// and keeping the triggering character for the others. Empty segments are dropped.
+/// ```rust
+/// use change_case::{split, Boundary};
+/// assert_eq!(
+///     split("XMLHttpRequest", &[Boundary::UpperUpperLower, Boundary::LowerUpper]),
+///     vec!["XML", "Http", "Request"]
+/// );
+/// assert_eq!(split("foo_bar", &[Boundary::Underscore]), vec!["foo", "bar"]);
+/// ```
+pub fn split(input: &str, boundaries: &[Boundary]) -> Vec<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if is_delimiter(c, boundaries) {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if i > 0 && splits_before(&chars, i, boundaries) && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
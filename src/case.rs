@@ -0,0 +1,117 @@
+use crate::{
+    camel_case, captial_case, change_case, constant_case, dot_case, header_case, lower_case,
+    param_case, pascal_case, path_case, sentence_case, snake_case, title_case, upper_case,
+    Boundary, Config,
+};
+
+/// The case a string can be converted to, used to pick a conversion at runtime via [convert]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    Snake,
+    Camel,
+    Pascal,
+    Kebab,
+    Constant,
+    Dot,
+    Path,
+    Header,
+    Sentence,
+    Title,
+    Capital,
+    Upper,
+    Lower,
+}
+
+/// Convert to the case given by a runtime [Case] value, so the target case can come from
+/// a CLI flag or config value instead of being chosen at compile time
+/// ```rust
+/// use change_case::{convert, Case};
+/// assert_eq!(convert("test string", Case::Snake), "test_string");
+/// assert_eq!(convert("test string", Case::Pascal), "TestString");
+/// ```
+pub fn convert(input: &str, case: Case) -> String {
+    match case {
+        Case::Snake => snake_case(input),
+        Case::Camel => camel_case(input),
+        Case::Pascal => pascal_case(input),
+        Case::Kebab => param_case(input),
+        Case::Constant => constant_case(input),
+        Case::Dot => dot_case(input),
+        Case::Path => path_case(input),
+        Case::Header => header_case(input),
+        Case::Sentence => sentence_case(input),
+        Case::Title => title_case(input),
+        Case::Capital => captial_case(input),
+        Case::Upper => upper_case(input),
+        Case::Lower => lower_case(input),
+    }
+}
+
+/// The word boundaries a string already in the given [Case] relies on, so a [Config] restricted
+/// to them only splits on the boundaries that case actually uses.
+pub(crate) fn boundaries(case: Case) -> Vec<Boundary> {
+    match case {
+        Case::Snake | Case::Constant => vec![Boundary::Underscore],
+        Case::Camel | Case::Pascal => vec![Boundary::LowerUpper, Boundary::UpperUpperLower],
+        Case::Kebab | Case::Header => vec![Boundary::Hyphen],
+        Case::Dot => vec![Boundary::Dot],
+        Case::Path => vec![Boundary::Slash],
+        Case::Sentence | Case::Title | Case::Capital | Case::Upper | Case::Lower => {
+            vec![Boundary::Space]
+        }
+    }
+}
+
+/// The [Config] that builds the given case, used by [convert_from_to]. `Title`,
+/// `Upper` and `Lower` don't go through [change_case] at all, so they have no `Config` to build.
+fn config_for(case: Case) -> Option<Config> {
+    let config = match case {
+        Case::Snake => Config::default()
+            .set_delimiter("_")
+            .set_transform(Box::new(crate::transform_lower_case)),
+        Case::Camel => Config::default()
+            .set_delimiter("")
+            .set_transform(Box::new(crate::transform_camel_case)),
+        Case::Pascal => Config::default()
+            .set_delimiter("")
+            .set_transform(Box::new(crate::transform_pascal_case)),
+        Case::Kebab => Config::default()
+            .set_delimiter("-")
+            .set_transform(Box::new(crate::transform_lower_case)),
+        Case::Constant => Config::default()
+            .set_delimiter("_")
+            .set_transform(Box::new(crate::transform_upper_case)),
+        Case::Dot => Config::default()
+            .set_delimiter(".")
+            .set_transform(Box::new(crate::transform_lower_case)),
+        Case::Path => Config::default()
+            .set_delimiter("/")
+            .set_transform(Box::new(crate::transform_lower_case)),
+        Case::Header => Config::default()
+            .set_delimiter("-")
+            .set_transform(Box::new(crate::transform_capital_case)),
+        Case::Sentence => Config::default()
+            .set_delimiter(" ")
+            .set_transform(Box::new(crate::transform_sentence_case)),
+        Case::Capital => Config::default()
+            .set_delimiter(" ")
+            .set_transform(Box::new(crate::transform_capital_case)),
+        Case::Title | Case::Upper | Case::Lower => return None,
+    };
+    Some(config)
+}
+
+/// Convert to the target case, constraining the split to the boundaries the source case uses.
+/// This prevents the common bug where re-casing an identifier mangles intentional capitalization,
+/// e.g. converting a string already known to be snake_case should only ever split on underscores.
+/// ```rust
+/// use change_case::{convert_from_to, snake_case, Case};
+/// assert_eq!(convert_from_to("my_HttpValue", Case::Snake, Case::Snake), "my_httpvalue");
+/// assert_eq!(snake_case("my_HttpValue"), "my_http_value");
+/// ```
+pub fn convert_from_to(input: &str, from: Case, to: Case) -> String {
+    match config_for(to) {
+        Some(config) => change_case(input, config.set_from_case(from)),
+        None => convert(input, to),
+    }
+}
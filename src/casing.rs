@@ -0,0 +1,74 @@
+use crate::{
+    camel_case, captial_case, constant_case, dot_case, header_case, lower_case, param_case,
+    pascal_case, path_case, sentence_case, snake_case, title_case, upper_case, Case,
+};
+
+/// Call case conversions as methods on anything that can be viewed as a `&str`
+/// ```rust
+/// use change_case::Casing;
+/// assert_eq!("HelloWorld".to_snake_case(), "hello_world");
+/// assert_eq!("hello world".to_pascal_case(), "HelloWorld");
+/// ```
+pub trait Casing {
+    /// Convert to the case given by a runtime [Case] value
+    fn to_case(&self, case: Case) -> String;
+    fn to_snake_case(&self) -> String;
+    fn to_camel_case(&self) -> String;
+    fn to_pascal_case(&self) -> String;
+    fn to_kebab_case(&self) -> String;
+    fn to_constant_case(&self) -> String;
+    fn to_dot_case(&self) -> String;
+    fn to_path_case(&self) -> String;
+    fn to_header_case(&self) -> String;
+    fn to_sentence_case(&self) -> String;
+    fn to_title_case(&self) -> String;
+    fn to_capital_case(&self) -> String;
+    fn to_upper_case(&self) -> String;
+    fn to_lower_case(&self) -> String;
+}
+
+impl<T: AsRef<str>> Casing for T {
+    fn to_case(&self, case: Case) -> String {
+        crate::case::convert(self.as_ref(), case)
+    }
+
+    fn to_snake_case(&self) -> String {
+        snake_case(self.as_ref())
+    }
+    fn to_camel_case(&self) -> String {
+        camel_case(self.as_ref())
+    }
+    fn to_pascal_case(&self) -> String {
+        pascal_case(self.as_ref())
+    }
+    fn to_kebab_case(&self) -> String {
+        param_case(self.as_ref())
+    }
+    fn to_constant_case(&self) -> String {
+        constant_case(self.as_ref())
+    }
+    fn to_dot_case(&self) -> String {
+        dot_case(self.as_ref())
+    }
+    fn to_path_case(&self) -> String {
+        path_case(self.as_ref())
+    }
+    fn to_header_case(&self) -> String {
+        header_case(self.as_ref())
+    }
+    fn to_sentence_case(&self) -> String {
+        sentence_case(self.as_ref())
+    }
+    fn to_title_case(&self) -> String {
+        title_case(self.as_ref())
+    }
+    fn to_capital_case(&self) -> String {
+        captial_case(self.as_ref())
+    }
+    fn to_upper_case(&self) -> String {
+        upper_case(self.as_ref())
+    }
+    fn to_lower_case(&self) -> String {
+        lower_case(self.as_ref())
+    }
+}
@@ -0,0 +1,132 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+lazy_static! {
+    static ref IRREGULAR_PLURALS: HashMap<&'static str, &'static str> = {
+        let mut m = HashMap::new();
+        m.insert("person", "people");
+        m.insert("child", "children");
+        m.insert("mouse", "mice");
+        m.insert("man", "men");
+        m.insert("woman", "women");
+        m.insert("tooth", "teeth");
+        m.insert("foot", "feet");
+        m.insert("goose", "geese");
+        m
+    };
+    static ref IRREGULAR_SINGULARS: HashMap<&'static str, &'static str> =
+        IRREGULAR_PLURALS.iter().map(|(singular, plural)| (*plural, *singular)).collect();
+    static ref UNCOUNTABLE: HashSet<&'static str> = {
+        let mut s = HashSet::new();
+        s.insert("fish");
+        s.insert("sheep");
+        s.insert("series");
+        s.insert("species");
+        s.insert("deer");
+        s.insert("moose");
+        s
+    };
+    static ref RE_CONSONANT_Y: Regex = Regex::new(r"(?i)[^aeiou]y$").unwrap();
+    static ref RE_SIBILANT: Regex = Regex::new(r"(?i)(s|ss|sh|ch|x|z)$").unwrap();
+    static ref RE_IES: Regex = Regex::new(r"(?i)[^aeiou]ies$").unwrap();
+    static ref RE_ES: Regex = Regex::new(r"(?i)(s|ss|sh|ch|x|z)es$").unwrap();
+}
+
+/// Change a word to its plural form
+/// ```rust
+/// use change_case::pluralize;
+/// assert_eq!(pluralize("cat"), "cats");
+/// assert_eq!(pluralize("bus"), "buses");
+/// assert_eq!(pluralize("city"), "cities");
+/// assert_eq!(pluralize("person"), "people");
+/// assert_eq!(pluralize("fish"), "fish");
+/// ```
+pub fn pluralize(input: &str) -> String {
+    let lower = input.to_lowercase();
+    if UNCOUNTABLE.contains(lower.as_str()) {
+        return input.to_string();
+    }
+    if let Some(plural) = IRREGULAR_PLURALS.get(lower.as_str()) {
+        return plural.to_string();
+    }
+    if RE_CONSONANT_Y.is_match(input) {
+        return format!("{}ies", &input[..input.len() - 1]);
+    }
+    if RE_SIBILANT.is_match(input) {
+        return format!("{}es", input);
+    }
+    format!("{}s", input)
+}
+
+/// Change a word to its singular form
+/// ```rust
+/// use change_case::singularize;
+/// assert_eq!(singularize("cats"), "cat");
+/// assert_eq!(singularize("buses"), "bus");
+/// assert_eq!(singularize("cities"), "city");
+/// assert_eq!(singularize("people"), "person");
+/// assert_eq!(singularize("fish"), "fish");
+/// ```
+pub fn singularize(input: &str) -> String {
+    let lower = input.to_lowercase();
+    if UNCOUNTABLE.contains(lower.as_str()) {
+        return input.to_string();
+    }
+    if let Some(singular) = IRREGULAR_SINGULARS.get(lower.as_str()) {
+        return singular.to_string();
+    }
+    if RE_IES.is_match(input) {
+        return format!("{}y", &input[..input.len() - 3]);
+    }
+    if RE_ES.is_match(input) {
+        return input[..input.len() - 2].to_string();
+    }
+    if let Some(stripped) = input.strip_suffix('s') {
+        return stripped.to_string();
+    }
+    input.to_string()
+}
+
+/// Append an ordinal suffix (`st`, `nd`, `rd`, `th`) to a number
+/// ```rust
+/// use change_case::ordinalize;
+/// assert_eq!(ordinalize("1"), "1st");
+/// assert_eq!(ordinalize("2"), "2nd");
+/// assert_eq!(ordinalize("3"), "3rd");
+/// assert_eq!(ordinalize("4"), "4th");
+/// assert_eq!(ordinalize("11"), "11th");
+/// assert_eq!(ordinalize("12"), "12th");
+/// assert_eq!(ordinalize("13"), "13th");
+/// assert_eq!(ordinalize("22"), "22nd");
+/// ```
+pub fn ordinalize(input: &str) -> String {
+    let last_two: String = input.chars().rev().take(2).collect::<String>().chars().rev().collect();
+    let suffix = match last_two.parse::<u32>() {
+        Ok(11..=13) => "th",
+        _ => match input.chars().last() {
+            Some('1') => "st",
+            Some('2') => "nd",
+            Some('3') => "rd",
+            _ => "th",
+        },
+    };
+    format!("{}{}", input, suffix)
+}
+
+/// Remove the ordinal suffix (`st`, `nd`, `rd`, `th`) from a number
+/// ```rust
+/// use change_case::deordinalize;
+/// assert_eq!(deordinalize("1st"), "1");
+/// assert_eq!(deordinalize("22nd"), "22");
+/// assert_eq!(deordinalize("3rd"), "3");
+/// assert_eq!(deordinalize("4th"), "4");
+/// ```
+pub fn deordinalize(input: &str) -> String {
+    for suffix in ["st", "nd", "rd", "th"] {
+        if let Some(stripped) = input.strip_suffix(suffix) {
+            return stripped.to_string();
+        }
+    }
+    input.to_string()
+}
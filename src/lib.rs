@@ -1,35 +1,44 @@
-use lazy_static::lazy_static;
-use regex::{Regex, Replacer};
+//! Convert strings between camelCase, PascalCase, snake_case, and more.
+//!
+//! The `inflection` feature gates `class_case` and the pluralize/singularize/ordinalize
+//! functions re-exported from the `inflection` module. It is off by default, so building with
+//! default features does not provide `class_case`; enable the feature to pull it in.
 
+mod boundary;
+mod case;
+mod casing;
+#[cfg(feature = "inflection")]
+mod inflection;
+mod slugify;
 mod title_case;
+pub use boundary::{split, Boundary};
+pub use case::{convert, convert_from_to, Case};
+pub use casing::Casing;
+#[cfg(feature = "inflection")]
+pub use inflection::{deordinalize, ordinalize, pluralize, singularize};
+pub use slugify::{slugify, slugify_with_config, SlugifyConfig};
 pub use title_case::title_case;
 
-lazy_static! {
-    static ref RE_SPLIT_1: Regex = Regex::new(r"([a-z0-9])([A-Z])").unwrap();
-    static ref RE_SPLIT_2: Regex = Regex::new(r"([A-Z])([A-Z][a-z])").unwrap();
-    static ref RE_STRIP: Regex = Regex::new(r"(?i)[^A-Z0-9]+").unwrap();
-}
-
 type Fransform = dyn Fn(&str, usize) -> String;
 
 /// Control the behavier of change case
 pub struct Config {
-    split_regex: Vec<Regex>,
-    strip_regex: Vec<Regex>,
+    boundaries: Vec<Boundary>,
     delimiter: String,
     transform: Box<Fransform>,
 }
 
 impl Config {
-    /// Change regex used to split into word segments
-    pub fn set_split_regex(mut self, value: Vec<Regex>) -> Self {
-        self.split_regex = value;
+    /// Change the word boundaries used to split into word segments
+    pub fn set_boundaries(mut self, value: Vec<Boundary>) -> Self {
+        self.boundaries = value;
         self
     }
-    /// Change regex used to remove extraneous characters
-    pub fn set_strip_regex(mut self, value: Vec<Regex>) -> Self {
-        self.strip_regex = value;
-        self
+    /// Constrain the word boundaries to only the ones the given source [Case] actually uses,
+    /// so re-casing an identifier doesn't re-segment capitalization the source case already settled.
+    /// See [convert_from_to](crate::convert_from_to) for the common case of also picking the target case.
+    pub fn set_from_case(self, case: Case) -> Self {
+        self.set_boundaries(case::boundaries(case))
     }
     /// Change value used between words (e.g. " ")
     pub fn set_delimiter(mut self, value: &str) -> Self {
@@ -46,8 +55,7 @@ impl Config {
 impl Default for Config {
     fn default() -> Self {
         Self {
-            split_regex: vec![RE_SPLIT_1.clone(), RE_SPLIT_2.clone()],
-            strip_regex: vec![RE_STRIP.clone()],
+            boundaries: boundary::defaults(),
             delimiter: " ".into(),
             transform: Box::new(|part: &str, _index: usize| part.to_lowercase()),
         }
@@ -56,40 +64,23 @@ impl Default for Config {
 
 /// Core function to change case
 /// ```rust
-/// use regex::Regex;
-/// use change_case::{change_case, Config};
-/// let config = Config::default()
-///     .set_split_regex(vec![Regex::new("([a-z])([A-Z0-9])").unwrap()]);
+/// use change_case::{change_case, Boundary, Config};
+/// let config = Config::default().set_boundaries(vec![Boundary::LowerDigit]);
 /// assert_eq!(change_case("camel2019", config), "camel 2019");
 /// assert_eq!(change_case("camel2019", Config::default()), "camel2019");
 /// ```
-
 pub fn change_case(input: &str, config: Config) -> String {
-    let result = replace(
-        input,
-        config.split_regex.iter().map(|v| (v, "$1\0$2")).collect(),
-    );
-    let result = replace(
-        result.as_str(),
-        config.strip_regex.iter().map(|v| (v, "\0")).collect(),
-    );
-    let result = result.trim_start_matches("\0").trim_end_matches("\0");
+    let words = split(input, &config.boundaries);
     let transform = config.transform;
 
-    let parts: Vec<String> = result
-        .split("\0")
+    let parts: Vec<String> = words
+        .iter()
         .enumerate()
-        .map(|(index, part)| (transform)(part, index))
+        .map(|(index, part)| (transform)(part.as_str(), index))
         .collect();
     parts.join(config.delimiter.as_str())
 }
 
-fn replace<R: Replacer>(input: &str, reps: Vec<(&Regex, R)>) -> String {
-    reps.into_iter().fold(input.to_string(), |acc, re| {
-        re.0.replace_all(acc.as_str(), re.1).to_string()
-    })
-}
-
 /// Change to upper case
 /// ```rust
 /// use change_case::upper_case;
@@ -111,7 +102,7 @@ pub fn upper_case(input: &str) -> String {
 /// assert_eq!(upper_case_first("TEST"), "TEST");
 /// ```
 pub fn upper_case_first(input: &str) -> String {
-    if input.len() == 0 {
+    if input.is_empty() {
         return String::new();
     }
     let (first, last) = input.split_at(1);
@@ -139,22 +130,22 @@ pub fn lower_case(input: &str) -> String {
 /// assert_eq!(lower_case_first("TEST"), "tEST");
 /// ```
 pub fn lower_case_first(input: &str) -> String {
-    if input.len() == 0 {
+    if input.is_empty() {
         return String::new();
     }
     let (first, last) = input.split_at(1);
     format!("{}{}", lower_case(first), last)
 }
 
-fn transform_pascal_case(input: &str, index: usize) -> String {
-    if input.len() == 0 {
+pub(crate) fn transform_pascal_case(input: &str, index: usize) -> String {
+    if input.is_empty() {
         return String::new();
     }
     let (first, last) = input.split_at(1);
     let mut first = upper_case(first);
     if index > 0 {
-        let first_char = first.chars().nth(0).unwrap();
-        if first_char >= '0' && first_char <= '9' {
+        let first_char = first.chars().next().unwrap();
+        if first_char.is_ascii_digit() {
             first = format!("_{}", first)
         }
     }
@@ -179,7 +170,23 @@ pub fn pascal_case(input: &str) -> String {
     change_case(input, config)
 }
 
-fn transform_camel_case(input: &str, index: usize) -> String {
+/// Change to class case, the singularized pascal case conventionally used for model/class names.
+///
+/// Requires the `inflection` feature; not available with default features.
+/// ```rust
+/// # #[cfg(feature = "inflection")]
+/// # {
+/// use change_case::class_case;
+/// assert_eq!(class_case("blog_posts"), "BlogPost");
+/// assert_eq!(class_case("user"), "User");
+/// # }
+/// ```
+#[cfg(feature = "inflection")]
+pub fn class_case(input: &str) -> String {
+    pascal_case(&inflection::singularize(input))
+}
+
+pub(crate) fn transform_camel_case(input: &str, index: usize) -> String {
     if index == 0 {
         return lower_case(input);
     }
@@ -205,7 +212,7 @@ pub fn camel_case(input: &str) -> String {
     change_case(input, config)
 }
 
-fn transform_capital_case(input: &str, _index: usize) -> String {
+pub(crate) fn transform_capital_case(input: &str, _index: usize) -> String {
     upper_case_first(lower_case(input).as_str())
 }
 
@@ -227,7 +234,7 @@ pub fn captial_case(input: &str) -> String {
     change_case(input, config)
 }
 
-fn transform_upper_case(input: &str, _index: usize) -> String {
+pub(crate) fn transform_upper_case(input: &str, _index: usize) -> String {
     upper_case(input)
 }
 
@@ -251,7 +258,7 @@ pub fn constant_case(input: &str) -> String {
     change_case(input, config)
 }
 
-fn transform_lower_case(input: &str, _index: usize) -> String {
+pub(crate) fn transform_lower_case(input: &str, _index: usize) -> String {
     lower_case(input)
 }
 
@@ -303,6 +310,8 @@ pub fn header_case(input: &str) -> String {
 /// assert_eq!(param_case("TestV2"), "test-v2");
 /// assert_eq!(param_case("version 1.2.10"), "version-1-2-10");
 /// assert_eq!(param_case("version 1.21.0"), "version-1-21-0");
+/// // Stray punctuation is dropped as a separator, not kept in the surrounding word.
+/// assert_eq!(param_case("Hello, World!"), "hello-world");
 /// ```
 pub fn param_case(input: &str) -> String {
     let config = Config::default()
@@ -329,7 +338,7 @@ pub fn path_case(input: &str) -> String {
     change_case(input, config)
 }
 
-fn transform_sentence_case(input: &str, index: usize) -> String {
+pub(crate) fn transform_sentence_case(input: &str, index: usize) -> String {
     let input = lower_case(input);
     if index == 0 {
         upper_case_first(input.as_str())
@@ -365,6 +374,7 @@ pub fn sentence_case(input: &str) -> String {
 /// assert_eq!(snake_case("test string"), "test_string");
 /// assert_eq!(snake_case("Test String"), "test_string");
 /// assert_eq!(snake_case("TestV2"), "test_v2");
+/// assert_eq!(snake_case("foo1Bar"), "foo1_bar");
 /// assert_eq!(snake_case("version 1.2.10"), "version_1_2_10");
 /// assert_eq!(snake_case("version 1.21.0"), "version_1_21_0");
 /// ```
@@ -388,7 +398,6 @@ pub fn snake_case(input: &str) -> String {
 pub fn swap_case(input: &str) -> String {
     input
         .chars()
-        .into_iter()
         .map(|v| {
             if v.is_lowercase() {
                 v.to_uppercase().to_string()
@@ -398,3 +407,63 @@ pub fn swap_case(input: &str) -> String {
         })
         .collect()
 }
+
+fn transform_toggle_case(input: &str, _index: usize) -> String {
+    lower_case_first(upper_case(input).as_str())
+}
+
+/// Change to toggle case (the opposite of capital case)
+/// ```rust
+/// use change_case::toggle_case;
+/// assert_eq!(toggle_case(""), "");
+/// assert_eq!(toggle_case("test"), "tEST");
+/// assert_eq!(toggle_case("test string"), "tEST sTRING");
+/// assert_eq!(toggle_case("Test String"), "tEST sTRING");
+/// ```
+pub fn toggle_case(input: &str) -> String {
+    let config = Config::default()
+        .set_delimiter(" ")
+        .set_transform(Box::new(transform_toggle_case));
+    change_case(input, config)
+}
+
+/// Change to alternating case, alternating lower/upper across every alphabetic character
+/// ```rust
+/// use change_case::alternating_case;
+/// assert_eq!(alternating_case(""), "");
+/// assert_eq!(alternating_case("test"), "tEsT");
+/// assert_eq!(alternating_case("test string"), "tEsT sTrInG");
+/// ```
+pub fn alternating_case(input: &str) -> String {
+    let mut upper = false;
+    input
+        .chars()
+        .map(|v| {
+            if !v.is_alphabetic() {
+                return v.to_string();
+            }
+            let result = if upper {
+                v.to_uppercase().to_string()
+            } else {
+                v.to_lowercase().to_string()
+            };
+            upper = !upper;
+            result
+        })
+        .collect()
+}
+
+/// Change to train case. An alias of [header_case]: in this crate both fully capitalize every
+/// hyphen-separated word, which is what other case-conversion crates (e.g. `convert_case`) call
+/// train case rather than header case. Provided under this name for readers coming from there.
+/// ```rust
+/// use change_case::train_case;
+/// assert_eq!(train_case(""), "");
+/// assert_eq!(train_case("test"), "Test");
+/// assert_eq!(train_case("test string"), "Test-String");
+/// assert_eq!(train_case("Test String"), "Test-String");
+/// assert_eq!(train_case("TestV2"), "Test-V2");
+/// ```
+pub fn train_case(input: &str) -> String {
+    header_case(input)
+}
@@ -0,0 +1,113 @@
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// Letters with no NFD decomposition that still need an ASCII fallback, e.g. `ø`/`æ`/`ß`.
+fn transliterate_char(c: char) -> Option<&'static str> {
+    match c {
+        'æ' => Some("ae"),
+        'Æ' => Some("AE"),
+        'œ' => Some("oe"),
+        'Œ' => Some("OE"),
+        'ø' => Some("o"),
+        'Ø' => Some("O"),
+        'ß' => Some("ss"),
+        'ð' => Some("d"),
+        'Ð' => Some("D"),
+        'þ' => Some("th"),
+        'Þ' => Some("Th"),
+        'ł' => Some("l"),
+        'Ł' => Some("L"),
+        'đ' => Some("d"),
+        'Đ' => Some("D"),
+        _ => None,
+    }
+}
+
+/// Control the behavier of [slugify_with_config]
+pub struct SlugifyConfig {
+    separator: char,
+    transliterate: bool,
+}
+
+impl SlugifyConfig {
+    /// Change the character used between words (default `-`)
+    pub fn set_separator(mut self, value: char) -> Self {
+        self.separator = value;
+        self
+    }
+    /// Change whether accented characters are folded down to their ASCII equivalent (default `true`)
+    pub fn set_transliterate(mut self, value: bool) -> Self {
+        self.transliterate = value;
+        self
+    }
+}
+
+impl Default for SlugifyConfig {
+    fn default() -> Self {
+        Self {
+            separator: '-',
+            transliterate: true,
+        }
+    }
+}
+
+/// Change to a URL-friendly slug
+/// ```rust
+/// use change_case::slugify;
+/// assert_eq!(slugify(""), "");
+/// assert_eq!(slugify("Piña Colada"), "pina-colada");
+/// assert_eq!(slugify("fooBar"), "foo-bar");
+/// assert_eq!(slugify("Hello, World!"), "hello-world");
+/// assert_eq!(slugify("Æther"), "aether");
+/// assert_eq!(slugify("smørrebrød"), "smorrebrod");
+/// ```
+pub fn slugify(input: &str) -> String {
+    slugify_with_config(input, SlugifyConfig::default())
+}
+
+/// Change to a URL-friendly slug, with control over the separator and transliteration
+/// ```rust
+/// use change_case::{slugify_with_config, SlugifyConfig};
+/// let config = SlugifyConfig::default().set_separator('_');
+/// assert_eq!(slugify_with_config("Piña Colada", config), "pina_colada");
+/// ```
+pub fn slugify_with_config(input: &str, config: SlugifyConfig) -> String {
+    let mut spaced = String::new();
+    let mut prev_lower = false;
+    for c in input.chars() {
+        if prev_lower && c.is_uppercase() {
+            spaced.push(' ');
+        }
+        spaced.push(c);
+        prev_lower = c.is_lowercase();
+    }
+
+    let folded: String = if config.transliterate {
+        spaced
+            .nfd()
+            .filter(|c| !is_combining_mark(*c))
+            .flat_map(|c| match transliterate_char(c) {
+                Some(replacement) => replacement.chars().collect::<Vec<_>>(),
+                None => vec![c],
+            })
+            .collect()
+    } else {
+        spaced
+    };
+    let lower = folded.to_lowercase();
+
+    let mut result = String::new();
+    let mut last_was_separator = true;
+    for c in lower.chars() {
+        if c.is_alphanumeric() {
+            result.push(c);
+            last_was_separator = false;
+        } else if !last_was_separator {
+            result.push(config.separator);
+            last_was_separator = true;
+        }
+    }
+    if result.ends_with(config.separator) {
+        result.pop();
+    }
+    result
+}
@@ -28,10 +28,10 @@ pub fn title_case(input: &str) -> String {
             // Ignore small words except at beginning or end.
             (!RE_SMALL_WORDS.is_match(token).unwrap() || index == 0 || index2 == input.len()) &&
             // Ignore URLs
-            (input.chars().nth(index2).map_or(true, |v| v != ':') ||
-                input.chars().nth(index2 + 1).map_or(false, |v| RE_WHITESPACE.is_match(v.to_string().as_str())))
+            (input.chars().nth(index2) != Some(':') ||
+                input.chars().nth(index2 + 1).is_some_and(|v| RE_WHITESPACE.is_match(v.to_string().as_str())))
         {
-            let new_token = RE_ALPHANUMERIC.replace(token, |v: &Captures| format!("{}", &v[0].to_uppercase()));
+            let new_token = RE_ALPHANUMERIC.replace(token, |v: &Captures| v[0].to_uppercase().to_string());
             result.push_str(new_token.as_ref())
         } else {
             result.push_str(token)